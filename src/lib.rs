@@ -4,7 +4,11 @@
 
 use std::{
     error::Error as StdError,
-    fmt, thread,
+    ffi::OsString,
+    fmt, fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    thread,
     time::{Duration, Instant},
 };
 
@@ -12,6 +16,7 @@ use bytes::Bytes;
 use digest::DynDigest;
 use reqwest::{
     blocking::{Client, ClientBuilder},
+    header::RANGE,
     Error as ReqwestError, IntoUrl, StatusCode,
 };
 
@@ -27,7 +32,9 @@ pub enum Error {
         ReqwestError,
     ),
 
-    /// HTTP response status is not `OK` (200).
+    /// HTTP response status is not in the accepted set, `200` by default.
+    ///
+    /// See [`DownloaderBuilder::accept_status`].
     StatusNotOk(
         /// HTTP response status.
         StatusCode,
@@ -43,8 +50,16 @@ pub enum Error {
 
     /// Download failed.
     DownloadFailed(
-        /// Errors, one error for each (re)try.
-        Vec<Error>,
+        /// Errors grouped per candidate URL, in the order the URLs were tried.
+        /// Each group pairs the URL with one error for each (re)try against it.
+        Vec<(String, Vec<Error>)>,
+    ),
+
+    /// I/O error while reading the response body or reading or writing the
+    /// downloaded file.
+    Io(
+        /// The error.
+        io::Error,
     ),
 }
 
@@ -59,13 +74,17 @@ impl fmt::Display for Error {
             Error::HashMismatch { got, expected } => {
                 write!(f, "hash mismatch\nGot     :{}\nExpected:{}", got, expected)
             }
-            Error::DownloadFailed(errors) => {
+            Error::DownloadFailed(groups) => {
                 write!(f, "download failed:")?;
-                for (index, error) in errors.iter().enumerate() {
-                    write!(f, "\n[{}]: {}", index, error)?;
+                for (url, errors) in groups {
+                    write!(f, "\n[{}]:", url)?;
+                    for (index, error) in errors.iter().enumerate() {
+                        write!(f, "\n  [{}]: {}", index, error)?;
+                    }
                 }
                 Ok(())
             }
+            Error::Io(inner) => inner.fmt(f),
         }
     }
 }
@@ -84,6 +103,12 @@ impl From<ReqwestError> for Error {
     }
 }
 
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
 // ======================================================================
 // Downloader - PUBLIC
 
@@ -94,7 +119,9 @@ pub struct Downloader {
     client: Client,
     min_interval: Duration,
     max_interval: Duration,
-    retry_delays: Vec<(Duration, Duration)>,
+    retry: Retry,
+    retry_if: Box<dyn Fn(&Error) -> bool>,
+    accept_status: Vec<StatusCode>,
     prev_download_start: Option<Instant>,
 }
 
@@ -116,7 +143,41 @@ impl Downloader {
     ///
     /// [simple usage]: crate#simple-usage
     pub fn get<U: IntoUrl>(&mut self, url: U) -> RequestBuilder {
-        RequestBuilder::new(self, self.client.get(url))
+        let inner = self.client.get(url);
+        RequestBuilder::new(self, vec![inner])
+    }
+
+    /// Begins building a request backed by several candidate `urls`.
+    ///
+    /// The URLs act as mirrors for a single logical download: [`RequestBuilder::send`]
+    /// (and its streaming counterparts) try them in order, each through the full
+    /// retry schedule, moving on to the next only once the current one is
+    /// exhausted. The [`hash`](RequestBuilder::hash) expectation is carried
+    /// across all of them, and on failure [`Error::DownloadFailed`] groups the
+    /// errors per URL so the caller can see which mirror failed and why.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::new()?;
+    /// let bytes = downloader
+    ///     .get_mirrors([
+    ///         "https://mirror1.example.com/file",
+    ///         "https://mirror2.example.com/file",
+    ///     ])
+    ///     .send()?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn get_mirrors<I, U>(&mut self, urls: I) -> RequestBuilder
+    where
+        I: IntoIterator<Item = U>,
+        U: IntoUrl,
+    {
+        let inner = urls.into_iter().map(|url| self.client.get(url)).collect();
+        RequestBuilder::new(self, inner)
     }
 
     /// Creates new [`Downloader`] with default configuration.
@@ -172,7 +233,9 @@ pub struct DownloaderBuilder {
     client_builder: ClientBuilder,
     min_interval: Duration,
     max_interval: Duration,
-    retry_delays: Vec<(Duration, Duration)>,
+    retry: Retry,
+    retry_if: Box<dyn Fn(&Error) -> bool>,
+    accept_status: Vec<StatusCode>,
 }
 
 impl Default for DownloaderBuilder {
@@ -192,7 +255,9 @@ impl DownloaderBuilder {
             client: self.client_builder.build()?,
             min_interval: self.min_interval,
             max_interval: self.max_interval,
-            retry_delays: self.retry_delays,
+            retry: self.retry,
+            retry_if: self.retry_if,
+            accept_status: self.accept_status,
             prev_download_start: None,
         })
     }
@@ -238,7 +303,9 @@ impl DownloaderBuilder {
             client_builder: Client::builder(),
             min_interval: Duration::ZERO,
             max_interval: Duration::ZERO,
-            retry_delays: Vec::new(),
+            retry: Retry::Delays(Vec::new()),
+            retry_if: Box::new(default_retry_if),
+            accept_status: vec![StatusCode::OK],
         }
     }
 
@@ -274,6 +341,9 @@ impl DownloaderBuilder {
     ///
     /// A random delay between given `min` and `max` is generated for each retry.
     ///
+    /// This replaces any schedule set with [`DownloaderBuilder::retry_backoff`];
+    /// the two modes are mutually exclusive and the last-set one wins.
+    ///
     /// # Panics
     ///
     /// If any item has `min > max`.
@@ -301,7 +371,121 @@ impl DownloaderBuilder {
         }
 
         DownloaderBuilder {
-            retry_delays: vec,
+            retry: Retry::Delays(vec),
+            ..self
+        }
+    }
+
+    /// Sets a decorrelated-jitter retry backoff, default is none.
+    ///
+    /// `attempts` bounds the number of retries just like the number of items
+    /// given to [`DownloaderBuilder::retry_delays`] does. `base` and `cap` are
+    /// the smallest and largest possible delay in seconds.
+    ///
+    /// Unlike [`DownloaderBuilder::retry_delays`] the delay is not fixed per
+    /// retry: a `last_delay` state (starting at zero) grows the average delay
+    /// over time to protect a downed server, while the randomization keeps some
+    /// retries quick and prevents concurrent downloaders from synchronizing
+    /// their retries against the same server. For each retry the delay is drawn
+    /// uniformly from `[base, max(base + 1 ms, last_delay * 3)]` with the upper
+    /// bound clamped to `cap`, then
+    /// stored as the new `last_delay`.
+    ///
+    /// This replaces any schedule set with [`DownloaderBuilder::retry_delays`];
+    /// the two modes are mutually exclusive and the last-set one wins.
+    ///
+    /// # Panics
+    ///
+    /// If `base > cap`.
+    ///
+    /// # Examples
+    ///
+    /// Configure five retries with `0.5 - 30.0` seconds decorrelated-jitter delay.
+    ///
+    /// ```rust
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::builder()
+    ///     .retry_backoff(5, 0.5, 30.0)
+    ///     .build()?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn retry_backoff(self, attempts: u32, base: f32, cap: f32) -> Self {
+        assert!(base <= cap);
+        DownloaderBuilder {
+            retry: Retry::Backoff {
+                attempts,
+                base: Duration::from_secs_f32(base),
+                cap: Duration::from_secs_f32(cap),
+            },
+            ..self
+        }
+    }
+
+    /// Sets a predicate deciding whether a failed attempt should be retried.
+    ///
+    /// The predicate is consulted with the [`Error`] of each failed attempt
+    /// before the retry delay. If it returns `false` the retries against the
+    /// current URL stop immediately, without waiting out the remaining retry
+    /// budget; the next candidate URL (see [`Downloader::get_mirrors`]) is then
+    /// tried, and once none is left [`RequestBuilder::send`] returns
+    /// [`Error::DownloadFailed`].
+    ///
+    /// The default retries transient failures — [`Error::Reqwest`] connection
+    /// and timeout errors, [`Error::Io`] connection-drop and timeout errors while
+    /// streaming a body, and [`Error::StatusNotOk`] with a `5xx` or `429`
+    /// status — but not `4xx` client errors, other reqwest errors, or
+    /// [`Error::HashMismatch`].
+    ///
+    /// # Examples
+    ///
+    /// Retry every failed attempt, ignoring the error.
+    ///
+    /// ```rust
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::builder()
+    ///     .retry_if(|_err| true)
+    ///     .build()?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn retry_if<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&Error) -> bool + 'static,
+    {
+        DownloaderBuilder {
+            retry_if: Box::new(predicate),
+            ..self
+        }
+    }
+
+    /// Sets the response statuses accepted as success, default is just `200`.
+    ///
+    /// [`RequestBuilder::send`] and its streaming counterparts return
+    /// [`Error::StatusNotOk`] for any response whose status is not in this set,
+    /// so widening it lets legitimately-successful responses like `206 Partial
+    /// Content` or `204 No Content` through while keeping the error meaningful
+    /// for genuinely unexpected statuses. A `206 Partial Content` answer to the
+    /// range request of [`RequestBuilder::resume`] is accepted even when it is
+    /// not in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ml_downloader::Downloader;
+    /// use reqwest::StatusCode;
+    ///
+    /// let mut downloader = Downloader::builder()
+    ///     .accept_status(&[StatusCode::OK, StatusCode::PARTIAL_CONTENT])
+    ///     .build()?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn accept_status(self, accept_status: &[StatusCode]) -> Self {
+        DownloaderBuilder {
+            accept_status: accept_status.to_vec(),
             ..self
         }
     }
@@ -317,8 +501,10 @@ impl DownloaderBuilder {
 /// [custom configuration]: crate#custom-configuration
 pub struct RequestBuilder<'a> {
     downloader: &'a mut Downloader,
-    inner: reqwest::blocking::RequestBuilder,
+    inner: Vec<reqwest::blocking::RequestBuilder>,
     hash: Option<(String, Box<dyn DynDigest>)>,
+    on_progress: Option<Box<dyn FnMut(u64, Option<u64>)>>,
+    resume: bool,
 }
 
 impl<'a> RequestBuilder<'a> {
@@ -347,58 +533,275 @@ impl<'a> RequestBuilder<'a> {
         }
     }
 
+    /// Sets a callback invoked with download progress while streaming.
+    ///
+    /// Only [`RequestBuilder::send_to_file`] and [`RequestBuilder::send_to_writer`]
+    /// report progress; [`RequestBuilder::send`] buffers the whole body at once
+    /// and ignores the callback.
+    ///
+    /// The callback is called with the number of bytes downloaded so far and,
+    /// when the response carries a `Content-Length` header, the total number of
+    /// bytes expected. It is invoked once at the start of each transfer —
+    /// including when a retry restarts the transfer — and again as each chunk
+    /// arrives. The starting count is `0`, except for a [`resume()`] transfer
+    /// that continues a partial file, where it is the already-downloaded offset.
+    ///
+    /// [`resume()`]: RequestBuilder::resume
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::new()?;
+    /// downloader
+    ///     .get("https://example.com/large.bin")
+    ///     .on_progress(|downloaded, total| match total {
+    ///         Some(total) => println!("{downloaded} / {total}"),
+    ///         None => println!("{downloaded}"),
+    ///     })
+    ///     .send_to_file("large.bin")?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn on_progress<F>(self, callback: F) -> Self
+    where
+        F: FnMut(u64, Option<u64>) + 'static,
+    {
+        RequestBuilder {
+            on_progress: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Resumes an interrupted [`RequestBuilder::send_to_file`] download.
+    ///
+    /// When the temporary file for the target path already holds `N` bytes the
+    /// request is issued with a `Range: bytes=N-` header and, on `206 Partial
+    /// Content`, the body is appended to the existing file instead of
+    /// refetching from byte zero. The configured [`hash`](RequestBuilder::hash)
+    /// digest is primed by replaying the already-downloaded prefix so the
+    /// completed file still verifies. If the server ignores the range and
+    /// answers `200 OK` the partial data is discarded and the transfer restarts
+    /// cleanly.
+    ///
+    /// This only affects [`RequestBuilder::send_to_file`]; the partial file is
+    /// left in place on failure so a later call can pick up where it stopped,
+    /// except on [`Error::HashMismatch`] of the completed file, which removes it.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::new()?;
+    /// downloader
+    ///     .get("https://example.com/large.bin")
+    ///     .resume()
+    ///     .send_to_file("large.bin")?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn resume(self) -> Self {
+        RequestBuilder {
+            resume: true,
+            ..self
+        }
+    }
+
     /// Creates download request and sends it to target URL, with retries.
     ///
     /// - Sleeps before starting download if needed.
     ///     - See [`DownloaderBuilder::interval`] and [`Downloader::sleep_until_ready`].
     /// - Number of retries and the delays inbetween them is configured with
-    ///   [`DownloaderBuilder::retry_delays`].
+    ///   [`DownloaderBuilder::retry_delays`] or [`DownloaderBuilder::retry_backoff`].
     ///
     /// See [simple usage] and [`RequestBuilder::hash`] for examples.
     ///
     /// [simple usage]: crate#simple-usage
     pub fn send(mut self) -> Result<Bytes, Error> {
-        let mut errors = Vec::with_capacity(self.downloader.retry_delays.len());
+        self.run_with_retries(|this, request| this.send_once(request))
+    }
 
-        self.downloader.sleep_until_ready();
+    /// Creates download request and streams the response body to `path`, with retries.
+    ///
+    /// Unlike [`RequestBuilder::send`] the body is never held in memory: it is
+    /// read in chunks and written straight to disk, feeding the configured
+    /// [`hash`](RequestBuilder::hash) digest incrementally as it goes so hash
+    /// verification works regardless of file size.
+    ///
+    /// The body is written to a temporary file next to `path` which is renamed
+    /// into place only after the status check and hash verification succeed; on
+    /// [`Error::StatusNotOk`] or [`Error::HashMismatch`] the temporary file is
+    /// removed. Retries start the transfer over into a freshly truncated
+    /// temporary file. With [`RequestBuilder::resume`] set, the temporary file
+    /// is instead kept on failure (other than a hash mismatch) and retries
+    /// continue from its end.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::new()?;
+    /// downloader
+    ///     .get("https://example.com/large.bin")
+    ///     .send_to_file("large.bin")?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn send_to_file<P: AsRef<Path>>(mut self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        let tmp = tmp_path(path);
+        let resume = self.resume;
 
-        let mut retry_count = 0;
-        loop {
-            self.downloader.prev_download_start = Some(Instant::now());
+        let result = self.run_with_retries(|this, request| this.stream_to_tmp(request, &tmp));
 
-            match self.send_once() {
-                Ok(bytes) => return Ok(bytes),
-                Err(error) => errors.push(error),
+        match result {
+            Ok(()) => {
+                fs::rename(&tmp, path)?;
+                Ok(())
             }
-
-            if retry_count == self.downloader.retry_delays.len() {
-                return Err(Error::DownloadFailed(errors));
+            Err(error) => {
+                // Keep the partial file in resume mode so a later `resume()`
+                // call can continue from where this one stopped.
+                if !resume {
+                    let _ = fs::remove_file(&tmp);
+                }
+                Err(error)
             }
-
-            let (min, max) = self.downloader.retry_delays[retry_count];
-            thread::sleep(random_duration(min, max));
-            retry_count += 1;
         }
     }
+
+    /// Creates download request and streams the response body to `writer`, with retries.
+    ///
+    /// Like [`RequestBuilder::send_to_file`] the body is read in chunks and fed
+    /// incrementally into the configured [`hash`](RequestBuilder::hash) digest
+    /// instead of being buffered in memory.
+    ///
+    /// Retries only stream while the response body is still untouched: once any
+    /// byte has been written to `writer`, every later attempt fails at once with
+    /// [`Error::Io`] without sending a request or touching `writer`, because
+    /// re-streaming into a non-seekable writer would leave the
+    /// earlier partial bytes in place and let a passing hash vouch for corrupt
+    /// output. Use [`RequestBuilder::send_to_file`] if you need the transfer to
+    /// survive a mid-body connection drop.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ml_downloader::Downloader;
+    ///
+    /// let mut downloader = Downloader::new()?;
+    /// let mut buf = Vec::new();
+    /// downloader
+    ///     .get("https://example.com/large.bin")
+    ///     .send_to_writer(&mut buf)?;
+    ///
+    /// # Ok::<(), ml_downloader::Error>(())
+    /// ```
+    pub fn send_to_writer<W: Write>(mut self, mut writer: W) -> Result<(), Error> {
+        let mut wrote = false;
+        self.run_with_retries(|this, request| {
+            if wrote {
+                // A previous attempt already handed partial bytes to this
+                // non-seekable writer; re-streaming would append a duplicate
+                // prefix, so fail instead of retrying over corrupt output.
+                return Err(Error::Io(io::Error::new(
+                    io::ErrorKind::Other,
+                    "writer already received partial data and cannot be retried",
+                )));
+            }
+            this.stream_once(request, &mut writer, &mut wrote)
+        })
+    }
 }
 
 // ======================================================================
 // RequestBuilder - PRIVATE
 
 impl<'a> RequestBuilder<'a> {
-    fn new(downloader: &'a mut Downloader, inner: reqwest::blocking::RequestBuilder) -> Self {
+    fn new(downloader: &'a mut Downloader, inner: Vec<reqwest::blocking::RequestBuilder>) -> Self {
         Self {
             downloader,
             inner,
             hash: None,
+            on_progress: None,
+            resume: false,
         }
     }
 
-    fn send_once(&mut self) -> Result<Bytes, Error> {
-        let response = self.inner.try_clone().unwrap().send()?;
+    /// Drives `attempt` across every candidate URL through the retry schedule.
+    ///
+    /// Sleeps before the first attempt if needed, then tries each URL in order —
+    /// each through the full retry schedule configured with
+    /// [`DownloaderBuilder::retry_delays`] / [`DownloaderBuilder::retry_backoff`]
+    /// and [`DownloaderBuilder::retry_if`] — returning the first success. If
+    /// every URL is exhausted the per-URL errors are grouped into
+    /// [`Error::DownloadFailed`].
+    fn run_with_retries<T, F>(&mut self, mut attempt: F) -> Result<T, Error>
+    where
+        F: FnMut(&mut Self, &reqwest::blocking::RequestBuilder) -> Result<T, Error>,
+    {
+        self.downloader.sleep_until_ready();
+
+        let inner = std::mem::take(&mut self.inner);
+        let mut groups = Vec::with_capacity(inner.len());
+        for request in &inner {
+            match self.attempt_url(request, &mut attempt) {
+                Ok(value) => return Ok(value),
+                Err(errors) => groups.push((request_url(request), errors)),
+            }
+        }
+
+        Err(Error::DownloadFailed(groups))
+    }
+
+    /// Retries `attempt` against a single `request` until success or exhaustion.
+    ///
+    /// Returns the errors of every failed (re)try, stopping early when
+    /// [`DownloaderBuilder::retry_if`] rejects the latest error.
+    fn attempt_url<T, F>(
+        &mut self,
+        request: &reqwest::blocking::RequestBuilder,
+        mut attempt: F,
+    ) -> Result<T, Vec<Error>>
+    where
+        F: FnMut(&mut Self, &reqwest::blocking::RequestBuilder) -> Result<T, Error>,
+    {
+        let retries = self.downloader.retry.retries();
+        let mut errors = Vec::with_capacity(retries);
+
+        let mut retry_count = 0;
+        let mut last_delay = Duration::ZERO;
+        loop {
+            self.downloader.prev_download_start = Some(Instant::now());
+
+            match attempt(self, request) {
+                Ok(value) => return Ok(value),
+                Err(error) => errors.push(error),
+            }
+
+            if !(self.downloader.retry_if)(errors.last().unwrap()) {
+                return Err(errors);
+            }
+
+            if retry_count == retries {
+                return Err(errors);
+            }
+
+            let delay = self.downloader.retry.delay(retry_count, last_delay);
+            thread::sleep(delay);
+            last_delay = delay;
+            retry_count += 1;
+        }
+    }
+
+    fn send_once(&mut self, request: &reqwest::blocking::RequestBuilder) -> Result<Bytes, Error> {
+        let response = request.try_clone().unwrap().send()?;
         let status = response.status();
 
-        if status != StatusCode::OK {
+        if !self.downloader.accept_status.contains(&status) {
             Err(Error::StatusNotOk(status))
         } else {
             let bytes = response.bytes()?;
@@ -419,11 +822,264 @@ impl<'a> RequestBuilder<'a> {
             Ok(bytes)
         }
     }
+
+    fn stream_once(
+        &mut self,
+        request: &reqwest::blocking::RequestBuilder,
+        writer: &mut dyn Write,
+        wrote: &mut bool,
+    ) -> Result<(), Error> {
+        let mut response = request.try_clone().unwrap().send()?;
+        let status = response.status();
+
+        if !self.downloader.accept_status.contains(&status) {
+            return Err(Error::StatusNotOk(status));
+        }
+
+        if let Some((_, digest)) = &mut self.hash {
+            digest.reset();
+        }
+
+        let total = response.content_length();
+        self.copy_body(&mut response, writer, wrote, 0, total)?;
+        self.verify_hash()
+    }
+
+    fn stream_to_tmp(
+        &mut self,
+        request: &reqwest::blocking::RequestBuilder,
+        tmp: &Path,
+    ) -> Result<(), Error> {
+        if self.resume {
+            self.stream_resume(request, tmp)
+        } else {
+            // Each attempt truncates the tmp file, so partial writes from a
+            // failed attempt are harmless and retrying is safe.
+            let mut file = fs::File::create(tmp)?;
+            let mut wrote = false;
+            self.stream_once(request, &mut file, &mut wrote)?;
+            file.flush()?;
+            Ok(())
+        }
+    }
+
+    fn stream_resume(
+        &mut self,
+        request: &reqwest::blocking::RequestBuilder,
+        tmp: &Path,
+    ) -> Result<(), Error> {
+        let existing = fs::metadata(tmp).map(|meta| meta.len()).unwrap_or(0);
+
+        let request = request.try_clone().unwrap();
+        let request = if existing > 0 {
+            request.header(RANGE, format!("bytes={}-", existing))
+        } else {
+            request
+        };
+
+        let mut response = request.send()?;
+        let status = response.status();
+
+        // A `206 Partial Content` answer to our own Range request is always a
+        // success here, even when the caller hasn't added it to `accept_status`.
+        let ranged_ok = existing > 0 && status == StatusCode::PARTIAL_CONTENT;
+        if !ranged_ok && !self.downloader.accept_status.contains(&status) {
+            return Err(Error::StatusNotOk(status));
+        }
+
+        // Only append when there is partial data and the server honored the
+        // range; a plain `200 OK` means the range was ignored, so restart.
+        let resuming = ranged_ok;
+
+        if let Some((_, digest)) = &mut self.hash {
+            digest.reset();
+        }
+
+        let mut file = if resuming {
+            // Prime the digest with the bytes already on disk before appending.
+            if self.hash.is_some() {
+                let mut prefix = fs::File::open(tmp)?;
+                let mut buf = [0u8; 8192];
+                loop {
+                    let read = prefix.read(&mut buf)?;
+                    if read == 0 {
+                        break;
+                    }
+                    if let Some((_, digest)) = &mut self.hash {
+                        digest.update(&buf[..read]);
+                    }
+                }
+            }
+            fs::OpenOptions::new().append(true).open(tmp)?
+        } else {
+            fs::File::create(tmp)?
+        };
+
+        let downloaded = if resuming { existing } else { 0 };
+        let total = response
+            .content_length()
+            .map(|len| if resuming { existing + len } else { len });
+
+        let mut wrote = false;
+        self.copy_body(&mut response, &mut file, &mut wrote, downloaded, total)?;
+        file.flush()?;
+        drop(file);
+
+        let result = self.verify_hash();
+        if matches!(result, Err(Error::HashMismatch { .. })) {
+            // The completed file is corrupt; drop it so a later `resume()` can't
+            // get stuck re-requesting a range past the full (bad) length.
+            let _ = fs::remove_file(tmp);
+        }
+        result
+    }
+
+    /// Copies `response` into `writer`, updating the digest and progress callback.
+    ///
+    /// Sets `wrote` to `true` as soon as any byte reaches `writer` so callers
+    /// streaming into a non-seekable sink can refuse to retry over it.
+    fn copy_body(
+        &mut self,
+        response: &mut reqwest::blocking::Response,
+        writer: &mut dyn Write,
+        wrote: &mut bool,
+        mut downloaded: u64,
+        total: Option<u64>,
+    ) -> Result<(), Error> {
+        if let Some(on_progress) = &mut self.on_progress {
+            on_progress(downloaded, total);
+        }
+
+        let mut buf = [0u8; 8192];
+        loop {
+            let read = response.read(&mut buf)?;
+            if read == 0 {
+                break;
+            }
+            if let Some((_, digest)) = &mut self.hash {
+                digest.update(&buf[..read]);
+            }
+            *wrote = true;
+            writer.write_all(&buf[..read])?;
+
+            downloaded += read as u64;
+            if let Some(on_progress) = &mut self.on_progress {
+                on_progress(downloaded, total);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finalizes the digest and checks it against the expected hash, if set.
+    fn verify_hash(&mut self) -> Result<(), Error> {
+        if let Some((expected, digest)) = &mut self.hash {
+            let mut got = vec![0; digest.output_size()];
+            digest.finalize_into_reset(got.as_mut()).unwrap();
+            let got = hex::encode(got);
+
+            if &got != expected {
+                return Err(Error::HashMismatch {
+                    got,
+                    expected: expected.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ======================================================================
+// Retry - PRIVATE
+
+/// Retry schedule driving [`RequestBuilder::send`]'s retry loop.
+enum Retry {
+    /// Fixed per-retry `(min, max)` delays; length defines the retry count.
+    Delays(Vec<(Duration, Duration)>),
+
+    /// Decorrelated-jitter backoff bounded by `attempts`.
+    Backoff {
+        attempts: u32,
+        base: Duration,
+        cap: Duration,
+    },
+}
+
+impl Retry {
+    /// Number of retries this schedule allows.
+    fn retries(&self) -> usize {
+        match self {
+            Retry::Delays(delays) => delays.len(),
+            Retry::Backoff { attempts, .. } => *attempts as usize,
+        }
+    }
+
+    /// Delay before retry number `retry_count`, given the previous delay.
+    fn delay(&self, retry_count: usize, last_delay: Duration) -> Duration {
+        match self {
+            Retry::Delays(delays) => {
+                let (min, max) = delays[retry_count];
+                random_duration(min, max)
+            }
+            Retry::Backoff { base, cap, .. } => {
+                let low = *base;
+                let high = (last_delay * 3)
+                    .max(low + Duration::from_millis(1))
+                    .min(*cap);
+                random_duration(low, high)
+            }
+        }
+    }
 }
 
 // ======================================================================
 // FUNCTIONS - PRIVATE
 
+/// Default [`DownloaderBuilder::retry_if`] predicate.
+///
+/// Retries transient failures only: reqwest connection and timeout errors,
+/// dropped connections surfacing as I/O errors while streaming a body, and
+/// `5xx`/`429` statuses.
+fn default_retry_if(error: &Error) -> bool {
+    match error {
+        Error::Reqwest(inner) => inner.is_timeout() || inner.is_connect(),
+        Error::StatusNotOk(status) => {
+            status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+        }
+        // Mid-transfer connection drops in the streaming paths reach us as an
+        // `io::Error` from `Response::read`, not as `Error::Reqwest`.
+        Error::Io(inner) => matches!(
+            inner.kind(),
+            io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::BrokenPipe
+                | io::ErrorKind::UnexpectedEof
+                | io::ErrorKind::TimedOut
+        ),
+        Error::HashMismatch { .. } | Error::DownloadFailed(_) => false,
+    }
+}
+
+/// Best-effort URL of `request` for [`Error::DownloadFailed`] grouping.
+fn request_url(request: &reqwest::blocking::RequestBuilder) -> String {
+    request
+        .try_clone()
+        .and_then(|request| request.build().ok())
+        .map(|request| request.url().to_string())
+        .unwrap_or_default()
+}
+
+/// Temporary download path for `path`, its file name suffixed with `.part`.
+fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_else(OsString::new);
+    name.push(".part");
+    path.with_file_name(name)
+}
+
 fn random_duration(min: Duration, max: Duration) -> Duration {
     Duration::from_micros(fastrand::u64(
         min.as_micros() as u64..=max.as_micros() as u64,